@@ -0,0 +1,55 @@
+//! Benchmarks for `LpAffineExpression::addInPlace`/`value`, which the
+//! integer-id + sorted-`Vec` rework in `src/pulp.rs` targets: merging two
+//! term lists becomes a single linear scan over sorted ids instead of one
+//! hash probe (and `String` clone) per term. Ids belong to `LpVariable`
+//! values, not names, so the expressions here share one set of variables.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use pulp_rs::{LpAffineExpression, LpCategory, LpProblem, LpVariable};
+
+const N_TERMS: usize = 20_000;
+
+fn build_vars(n: usize) -> Vec<LpVariable> {
+    (0..n)
+        .map(|i| LpVariable::init(&format!("v{i}"), Some(0.0), None, LpCategory::Continuous))
+        .collect()
+}
+
+fn build_expr(vars: &[LpVariable]) -> LpAffineExpression {
+    let mut expr = LpAffineExpression::init();
+    for (i, var) in vars.iter().enumerate() {
+        expr.add_term(var, (i as f64) + 1.0);
+    }
+    expr
+}
+
+fn bench_add_in_place(c: &mut Criterion) {
+    // Same variables on both sides, so every term merges rather than
+    // just appending -- this is the path addInPlace spends its time in.
+    let vars = build_vars(N_TERMS);
+    let a = build_expr(&vars);
+    let b = build_expr(&vars);
+    c.bench_function("addInPlace 20k overlapping terms", |bencher| {
+        bencher.iter(|| {
+            let mut lhs = a.clone();
+            lhs.addInPlace(black_box(&b), 1.0);
+            black_box(&lhs);
+        });
+    });
+}
+
+fn bench_value(c: &mut Criterion) {
+    let vars = build_vars(N_TERMS);
+    let expr = build_expr(&vars);
+    let mut problem = LpProblem::new("bench", 1);
+    for mut var in vars {
+        var.set_value(1.0);
+        problem.addVariable(var);
+    }
+    c.bench_function("value over 20k terms", |bencher| {
+        bencher.iter(|| black_box(expr.value(black_box(&problem))));
+    });
+}
+
+criterion_group!(benches, bench_add_in_place, bench_value);
+criterion_main!(benches);
@@ -0,0 +1,187 @@
+//! Branch-and-bound driver layered on top of the continuous simplex in
+//! `simplex`, so `Integer`/`Binary` variables actually solve as a MIP
+//! instead of just returning the (possibly fractional) LP relaxation.
+
+use crate::pulp::{LpProblem, LpStatus};
+use crate::simplex::{self, BoundOverrides, LpSolution};
+use std::collections::HashMap;
+
+const EPS_INT: f64 = 1e-6;
+const NODE_LIMIT: usize = 10_000;
+
+struct Incumbent {
+    objective: f64,
+    values: HashMap<String, f64>,
+}
+
+/// Solves `problem` as a MIP: relax to an LP, and if any integer-categorized
+/// variable lands on a fractional value, branch on the most-fractional such
+/// variable by tightening its bounds and recursing. Depth-first with a node
+/// limit; returns the best incumbent found, `Infeasible` if none exists and
+/// the search ran to completion, or `Undefined` if the node limit was hit
+/// before the search could prove either outcome.
+pub(crate) fn solve_mip(problem: &LpProblem) -> LpSolution {
+    let mut overrides = BoundOverrides::new();
+    let mut incumbent: Option<Incumbent> = None;
+    let mut nodes = 0usize;
+    let mut truncated = false;
+    branch(problem, &mut overrides, &mut incumbent, &mut nodes, &mut truncated);
+
+    // A truncated search can't prove optimality even when it found an
+    // incumbent (a better solution may be sitting in an unexplored branch),
+    // and can't prove infeasibility when it didn't -- either way, report
+    // `Undefined` rather than asserting an outcome the search never reached.
+    let status = if truncated {
+        LpStatus::Undefined
+    } else if incumbent.is_some() {
+        LpStatus::Optimal
+    } else {
+        LpStatus::Infeasible
+    };
+
+    match incumbent {
+        Some(inc) => LpSolution {
+            status,
+            objective: inc.objective,
+            values: inc.values,
+            dj: HashMap::new(),
+            pi: HashMap::new(),
+            slack: HashMap::new(),
+        },
+        None => LpSolution {
+            status,
+            objective: 0.0,
+            values: HashMap::new(),
+            dj: HashMap::new(),
+            pi: HashMap::new(),
+            slack: HashMap::new(),
+        },
+    }
+}
+
+fn branch(
+    problem: &LpProblem,
+    overrides: &mut BoundOverrides,
+    incumbent: &mut Option<Incumbent>,
+    nodes: &mut usize,
+    truncated: &mut bool,
+) {
+    if *nodes >= NODE_LIMIT {
+        *truncated = true;
+        return;
+    }
+    *nodes += 1;
+
+    let relaxed = simplex::solve_relaxation(problem, overrides);
+    if relaxed.status != LpStatus::Optimal {
+        return;
+    }
+
+    // Prune if this node's relaxation can't beat the incumbent.
+    if let Some(inc) = incumbent.as_ref() {
+        let cannot_improve = if problem.sense == -1 {
+            relaxed.objective <= inc.objective + EPS_INT
+        } else {
+            relaxed.objective >= inc.objective - EPS_INT
+        };
+        if cannot_improve {
+            return;
+        }
+    }
+
+    // Pick the most-fractional integer-categorized variable, if any.
+    let mut branch_var: Option<(String, f64)> = None;
+    let mut worst_frac = EPS_INT;
+    for var in problem.variables() {
+        if !var.isInteger() {
+            continue;
+        }
+        let Some(&v) = relaxed.values.get(&var.name) else {
+            continue;
+        };
+        let frac = (v - v.round()).abs();
+        if frac > worst_frac {
+            worst_frac = frac;
+            branch_var = Some((var.name.clone(), v));
+        }
+    }
+
+    let Some((name, v)) = branch_var else {
+        // Integer-feasible leaf: consider it as a new incumbent.
+        let is_better = match incumbent.as_ref() {
+            None => true,
+            Some(inc) => {
+                if problem.sense == -1 {
+                    relaxed.objective > inc.objective + EPS_INT
+                } else {
+                    relaxed.objective < inc.objective - EPS_INT
+                }
+            }
+        };
+        if is_better {
+            *incumbent = Some(Incumbent {
+                objective: relaxed.objective,
+                values: relaxed.values,
+            });
+        }
+        return;
+    };
+
+    let bounds = problem
+        .variable_by_name(&name)
+        .map(|var| (var.lowBound, var.upBound))
+        .unwrap_or((None, None));
+    let original = overrides.get(&name).copied().unwrap_or(bounds);
+
+    overrides.insert(name.clone(), (original.0, Some(v.floor())));
+    branch(problem, overrides, incumbent, nodes, truncated);
+
+    overrides.insert(name.clone(), (Some(v.ceil()), original.1));
+    branch(problem, overrides, incumbent, nodes, truncated);
+
+    overrides.insert(name, original);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pulp::{LpAffineExpression, LpCategory, LpConstraint, LpConstraintSense, LpVariable};
+
+    #[test]
+    fn branches_to_an_integer_optimum_from_a_fractional_relaxation() {
+        // max x s.t. 2x <= 7, x integer: the LP relaxation is x=3.5, so the
+        // MIP driver must branch at least once to land on the true optimum.
+        let mut p = LpProblem::new("mip", -1);
+        let x = LpVariable::init("x", Some(0.0), None, LpCategory::Integer);
+        p.addVariable(x.clone());
+        let mut obj = LpAffineExpression::init();
+        obj.add_term(&x, 1.0);
+        p.setObjective(obj);
+        let mut cap = LpAffineExpression::init();
+        cap.add_term(&x, 2.0);
+        p.addConstraint(
+            "cap",
+            LpConstraint::new(cap, LpConstraintSense::Le, 7.0, Some("cap".into())),
+        );
+
+        let sol = solve_mip(&p);
+        assert_eq!(sol.status, LpStatus::Optimal);
+        assert!((sol.values.get("x").copied().unwrap() - 3.0).abs() < 1e-6);
+        assert!((sol.objective - 3.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn reports_infeasible_when_no_integer_point_exists() {
+        // x integer, 0.1 <= x <= 0.4: no integer lies in that range, so
+        // both branches (x <= 0 and x >= 1) are infeasible.
+        let mut p = LpProblem::new("mip-infeasible", 1);
+        let x = LpVariable::init("x", Some(0.1), Some(0.4), LpCategory::Integer);
+        p.addVariable(x.clone());
+        let mut obj = LpAffineExpression::init();
+        obj.add_term(&x, 1.0);
+        p.setObjective(obj);
+
+        let sol = solve_mip(&p);
+        assert_eq!(sol.status, LpStatus::Infeasible);
+    }
+}
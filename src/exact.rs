@@ -0,0 +1,534 @@
+//! Exact rational-arithmetic solving, parallel to the f64 path in `simplex`.
+//!
+//! `valid`/`round`/`roundedValue`/`infeasibilityGap` on the f64 side are all
+//! built around `eps` fudge factors, which makes degenerate or ill-scaled
+//! LPs give unstable answers. `ExactLpProblem` is a rational-coefficient
+//! mirror of `LpProblem` (terms keyed on variable name mapping to
+//! `BigRational`, plus a rational constant and rhs) with its own two-phase
+//! simplex that never rounds: basic feasible solutions come out exact, so
+//! `eps` is simply 0. Coefficients are backed by `BigInt` rather than a
+//! fixed-width integer -- Gauss-Jordan pivoting routinely produces
+//! numerators/denominators that outgrow an i64 well before the tableau does,
+//! and a silently wrapped "exact" answer would be worse than an f64 one. It's
+//! a separate, opt-in type rather than a mode flag on `LpProblem` -- users
+//! who need verifiable results (e.g. proving infeasibility) build their
+//! model with exact data from the start, while the existing f64 `LpProblem`
+//! stays the default for speed.
+
+use crate::pulp::{LpConstraintSense, LpStatus};
+use num_rational::BigRational;
+use num_traits::{One, Zero};
+use std::collections::HashMap;
+
+/// The exact-arithmetic type used throughout this module.
+pub type Rat = BigRational;
+
+#[derive(Debug, Clone, Default)]
+pub struct LpAffineExpressionExact {
+    pub terms: HashMap<String, Rat>,
+    pub constant: Rat,
+}
+
+impl LpAffineExpressionExact {
+    pub fn new() -> Self {
+        Self {
+            terms: HashMap::new(),
+            constant: Rat::zero(),
+        }
+    }
+
+    pub fn add_term(&mut self, name: &str, coeff: Rat) {
+        *self.terms.entry(name.to_string()).or_insert_with(Rat::zero) += coeff;
+    }
+
+    pub fn value(&self, values: &HashMap<String, Rat>) -> Option<Rat> {
+        let mut s = self.constant.clone();
+        for (name, coeff) in &self.terms {
+            s += values.get(name)?.clone() * coeff.clone();
+        }
+        Some(s)
+    }
+}
+
+/// Exact counterpart of `LpVariable::roundedValue`: returns the value
+/// unchanged if it is already an integer, `None` otherwise. There is no
+/// `eps` here -- a rational is either integral or it isn't.
+fn rounded_value_exact(v: Rat) -> Option<Rat> {
+    if v.is_integer() {
+        Some(v)
+    } else {
+        None
+    }
+}
+
+/// Exact counterpart of `LpVariable::valid`: bounds are checked with exact
+/// equality/inequality, no tolerance.
+fn valid_exact(value: &Rat, low_bound: Option<&Rat>, up_bound: Option<&Rat>) -> bool {
+    if let Some(up) = up_bound {
+        if value > up {
+            return false;
+        }
+    }
+    if let Some(low) = low_bound {
+        if value < low {
+            return false;
+        }
+    }
+    true
+}
+
+/// A standalone, rational-coefficient mirror of `LpProblem` for models where
+/// correctness matters more than speed.
+#[derive(Debug, Clone)]
+pub struct ExactLpProblem {
+    pub sense: i32,
+    pub objective: LpAffineExpressionExact,
+    pub constraints: Vec<(String, LpAffineExpressionExact, LpConstraintSense, Rat)>,
+    pub bounds: HashMap<String, (Rat, Option<Rat>)>,
+    pub status: LpStatus,
+    pub objectiveValue: Option<Rat>,
+    pub variableValues: HashMap<String, Rat>,
+}
+
+impl ExactLpProblem {
+    pub fn new(sense: i32) -> Self {
+        Self {
+            sense,
+            objective: LpAffineExpressionExact::new(),
+            constraints: Vec::new(),
+            bounds: HashMap::new(),
+            status: LpStatus::NotSolved,
+            objectiveValue: None,
+            variableValues: HashMap::new(),
+        }
+    }
+
+    pub fn setObjective(&mut self, obj: LpAffineExpressionExact) {
+        self.objective = obj;
+    }
+
+    pub fn addConstraint(
+        &mut self,
+        name: &str,
+        expr: LpAffineExpressionExact,
+        sense: LpConstraintSense,
+        rhs: Rat,
+    ) {
+        self.constraints.push((name.to_string(), expr, sense, rhs));
+    }
+
+    pub fn setBounds(&mut self, name: &str, low: Rat, up: Option<Rat>) {
+        self.bounds.insert(name.to_string(), (low, up));
+    }
+
+    pub fn solve(&mut self) -> LpStatus {
+        let (status, values, objective) = solve_exact(self);
+        self.status = status;
+        self.variableValues = values;
+        self.objectiveValue = objective;
+        self.status
+    }
+
+    /// Exact counterpart of `LpVariable::valid`/`LpConstraint::valid`: every
+    /// solved variable value must respect its bounds and every constraint
+    /// its sense, checked with exact rational comparisons (`eps = 0`).
+    pub fn valid(&self) -> bool {
+        for (name, (low, up)) in &self.bounds {
+            let Some(v) = self.variableValues.get(name) else {
+                return false;
+            };
+            if !valid_exact(v, Some(low), up.as_ref()) {
+                return false;
+            }
+        }
+        for (_, expr, sense, rhs) in &self.constraints {
+            let Some(val) = expr.value(&self.variableValues) else {
+                return false;
+            };
+            let ok = match sense {
+                LpConstraintSense::Eq => val == *rhs,
+                LpConstraintSense::Le => val <= *rhs,
+                LpConstraintSense::Ge => val >= *rhs,
+            };
+            if !ok {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Exact counterpart of `LpVariable::roundedValue`: the solved value of
+    /// `name` if it is already an integer, `None` otherwise (no `eps`).
+    pub fn roundedValue(&self, name: &str) -> Option<Rat> {
+        rounded_value_exact(self.variableValues.get(name)?.clone())
+    }
+}
+
+struct Row {
+    coeffs: Vec<Rat>,
+    rhs: Rat,
+    sense: LpConstraintSense,
+}
+
+fn pivot(tab: &mut [Vec<Rat>], obj: &mut [Rat], pr: usize, pc: usize) {
+    let pivot_val = tab[pr][pc].clone();
+    for v in tab[pr].iter_mut() {
+        *v = v.clone() / pivot_val.clone();
+    }
+    let pivot_row = tab[pr].clone();
+    for (r, row) in tab.iter_mut().enumerate() {
+        if r == pr {
+            continue;
+        }
+        let factor = row[pc].clone();
+        if factor != Rat::zero() {
+            for (c, v) in row.iter_mut().enumerate() {
+                *v = v.clone() - factor.clone() * pivot_row[c].clone();
+            }
+        }
+    }
+    let factor = obj[pc].clone();
+    if factor != Rat::zero() {
+        for (c, v) in obj.iter_mut().enumerate() {
+            *v = v.clone() - factor.clone() * pivot_row[c].clone();
+        }
+    }
+}
+
+/// Bland's rule (smallest-index entering column, smallest-index tie-break on
+/// the leaving row) rather than Dantzig's most-negative rule: with exact
+/// arithmetic there's no epsilon to perturb away degenerate cycling, so we
+/// need the anti-cycling guarantee instead.
+fn run_simplex(
+    tab: &mut [Vec<Rat>],
+    obj: &mut [Rat],
+    basis: &mut [usize],
+    cols_total: usize,
+    is_artificial: &[bool],
+) -> bool {
+    let zero = Rat::zero();
+    loop {
+        let mut enter = None;
+        for j in 0..cols_total {
+            if is_artificial[j] {
+                continue;
+            }
+            if obj[j] < zero {
+                enter = Some(j);
+                break;
+            }
+        }
+        let Some(pc) = enter else {
+            return true;
+        };
+
+        let mut leave = None;
+        let mut best_ratio: Option<Rat> = None;
+        for i in 0..tab.len() {
+            let a = &tab[i][pc];
+            if *a > zero {
+                let ratio = tab[i][cols_total].clone() / a.clone();
+                let better = match &best_ratio {
+                    None => true,
+                    Some(best) => {
+                        ratio < *best
+                            || (ratio == *best && leave.map_or(true, |l| basis[i] < basis[l]))
+                    }
+                };
+                if better {
+                    best_ratio = Some(ratio);
+                    leave = Some(i);
+                }
+            }
+        }
+        let Some(pr) = leave else {
+            return false;
+        };
+        pivot(tab, obj, pr, pc);
+        basis[pr] = pc;
+    }
+}
+
+fn solve_exact(problem: &ExactLpProblem) -> (LpStatus, HashMap<String, Rat>, Option<Rat>) {
+    let zero = Rat::zero();
+    let one = Rat::one();
+
+    let mut names: Vec<String> = problem.bounds.keys().cloned().collect();
+    for name in problem.objective.terms.keys() {
+        if !names.contains(name) {
+            names.push(name.clone());
+        }
+    }
+    for (_, expr, _, _) in &problem.constraints {
+        for name in expr.terms.keys() {
+            if !names.contains(name) {
+                names.push(name.clone());
+            }
+        }
+    }
+    names.sort();
+    let n = names.len();
+    let index: HashMap<&str, usize> = names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| (name.as_str(), i))
+        .collect();
+
+    let mut low_shift = vec![zero.clone(); n];
+    let mut up_shifted: Vec<Option<Rat>> = vec![None; n];
+    for (j, name) in names.iter().enumerate() {
+        let (low, up) = problem
+            .bounds
+            .get(name)
+            .cloned()
+            .unwrap_or_else(|| (zero.clone(), None));
+        up_shifted[j] = up.map(|u| u - low.clone());
+        low_shift[j] = low;
+    }
+
+    let mut rows: Vec<Row> = Vec::with_capacity(problem.constraints.len() + n);
+    for (_, expr, sense, rhs) in &problem.constraints {
+        let mut coeffs = vec![zero.clone(); n];
+        for (var_name, coeff) in &expr.terms {
+            if let Some(&j) = index.get(var_name.as_str()) {
+                coeffs[j] = coeffs[j].clone() + coeff.clone();
+            }
+        }
+        let shift_adjust = coeffs
+            .iter()
+            .zip(low_shift.iter())
+            .fold(zero.clone(), |acc, (a, b)| acc + a.clone() * b.clone());
+        let rhs_adj = rhs.clone() - expr.constant.clone() - shift_adjust;
+        rows.push(Row {
+            coeffs,
+            rhs: rhs_adj,
+            sense: *sense,
+        });
+    }
+    for (j, up) in up_shifted.iter().enumerate() {
+        if let Some(u) = up {
+            let mut coeffs = vec![zero.clone(); n];
+            coeffs[j] = one.clone();
+            rows.push(Row {
+                coeffs,
+                rhs: u.clone(),
+                sense: LpConstraintSense::Le,
+            });
+        }
+    }
+
+    for row in rows.iter_mut() {
+        if row.rhs < zero {
+            for c in row.coeffs.iter_mut() {
+                *c = -c.clone();
+            }
+            row.rhs = -row.rhs.clone();
+            row.sense = match row.sense {
+                LpConstraintSense::Le => LpConstraintSense::Ge,
+                LpConstraintSense::Ge => LpConstraintSense::Le,
+                LpConstraintSense::Eq => LpConstraintSense::Eq,
+            };
+        }
+    }
+
+    let mut slack_col_of: Vec<Option<usize>> = vec![None; rows.len()];
+    let mut surplus_col_of: Vec<Option<usize>> = vec![None; rows.len()];
+    let mut artificial_col_of: Vec<Option<usize>> = vec![None; rows.len()];
+    let mut next_col = n;
+    for (i, row) in rows.iter().enumerate() {
+        match row.sense {
+            LpConstraintSense::Le => {
+                slack_col_of[i] = Some(next_col);
+                next_col += 1;
+            }
+            LpConstraintSense::Ge => {
+                surplus_col_of[i] = Some(next_col);
+                next_col += 1;
+                artificial_col_of[i] = Some(next_col);
+                next_col += 1;
+            }
+            LpConstraintSense::Eq => {
+                artificial_col_of[i] = Some(next_col);
+                next_col += 1;
+            }
+        }
+    }
+    let cols_total = next_col;
+    let mut is_artificial = vec![false; cols_total];
+    for c in artificial_col_of.iter().flatten() {
+        is_artificial[*c] = true;
+    }
+
+    let mut tab = vec![vec![zero.clone(); cols_total + 1]; rows.len()];
+    let mut basis = vec![0usize; rows.len()];
+    for (i, row) in rows.iter().enumerate() {
+        tab[i][..n].clone_from_slice(&row.coeffs);
+        if let Some(c) = slack_col_of[i] {
+            tab[i][c] = one.clone();
+            basis[i] = c;
+        }
+        if let Some(c) = surplus_col_of[i] {
+            tab[i][c] = -one.clone();
+        }
+        if let Some(c) = artificial_col_of[i] {
+            tab[i][c] = one.clone();
+            basis[i] = c;
+        }
+        tab[i][cols_total] = row.rhs.clone();
+    }
+
+    if artificial_col_of.iter().any(Option::is_some) {
+        let mut w = vec![zero.clone(); cols_total + 1];
+        for (j, is_art) in is_artificial.iter().enumerate() {
+            w[j] = if *is_art { one.clone() } else { zero.clone() };
+        }
+        for i in 0..rows.len() {
+            if is_artificial[basis[i]] {
+                for c in 0..=cols_total {
+                    w[c] = w[c].clone() - tab[i][c].clone();
+                }
+            }
+        }
+        run_simplex(&mut tab, &mut w, &mut basis, cols_total, &is_artificial);
+
+        let phase1_obj: Rat = (0..rows.len())
+            .filter(|&i| is_artificial[basis[i]])
+            .fold(zero.clone(), |acc, i| acc + tab[i][cols_total].clone());
+        if phase1_obj != zero {
+            return (LpStatus::Infeasible, HashMap::new(), None);
+        }
+    }
+
+    // Phase 2: optimize the real objective; artificials stay locked out of entry.
+    let mut c2 = vec![zero.clone(); cols_total];
+    let sense_factor = if problem.sense == -1 {
+        -one.clone()
+    } else {
+        one.clone()
+    };
+    for (var_name, coeff) in &problem.objective.terms {
+        if let Some(&j) = index.get(var_name.as_str()) {
+            c2[j] = c2[j].clone() + sense_factor.clone() * coeff.clone();
+        }
+    }
+    let mut z = vec![zero.clone(); cols_total + 1];
+    z[..cols_total].clone_from_slice(&c2);
+    for i in 0..rows.len() {
+        let cb = c2[basis[i]].clone();
+        if cb != zero {
+            for c in 0..=cols_total {
+                z[c] = z[c].clone() - cb.clone() * tab[i][c].clone();
+            }
+        }
+    }
+
+    // Drive any artificial still in the basis (at value 0, since phase 1
+    // reached a zero objective) out before phase 2: pivot() folds every
+    // row's rhs into later pivots, so an artificial left basic can pick up a
+    // hidden nonzero value that never shows up in the reported solution.
+    for i in 0..rows.len() {
+        if !is_artificial[basis[i]] {
+            continue;
+        }
+        let pc = (0..cols_total).find(|&c| !is_artificial[c] && tab[i][c] != zero);
+        if let Some(pc) = pc {
+            pivot(&mut tab, &mut z, i, pc);
+            basis[i] = pc;
+        }
+        // else: row is redundant (linearly dependent on the others); leave
+        // the artificial basic at 0, which is harmless since it can never
+        // re-enter.
+    }
+
+    let optimal = run_simplex(&mut tab, &mut z, &mut basis, cols_total, &is_artificial);
+    if !optimal {
+        return (LpStatus::Unbounded, HashMap::new(), None);
+    }
+
+    let mut shifted_values = vec![zero.clone(); n];
+    for (i, &b) in basis.iter().enumerate() {
+        if b < n {
+            shifted_values[b] = tab[i][cols_total].clone();
+        }
+    }
+
+    let mut values = HashMap::new();
+    for (j, name) in names.iter().enumerate() {
+        values.insert(name.clone(), shifted_values[j].clone() + low_shift[j].clone());
+    }
+
+    let objective = problem.objective.constant.clone()
+        + problem
+            .objective
+            .terms
+            .iter()
+            .map(|(name, coeff)| {
+                values.get(name).cloned().unwrap_or_else(|| zero.clone()) * coeff.clone()
+            })
+            .fold(zero.clone(), |acc, x| acc + x);
+
+    (LpStatus::Optimal, values, Some(objective))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::BigInt;
+
+    fn r(n: i64, d: i64) -> Rat {
+        Rat::new(BigInt::from(n), BigInt::from(d))
+    }
+
+    #[test]
+    fn solves_an_exact_fractional_optimum() {
+        // max x s.t. 3x <= 10: the true optimum is the fraction 10/3, not
+        // any float's approximation of it.
+        let mut p = ExactLpProblem::new(-1);
+        let mut obj = LpAffineExpressionExact::new();
+        obj.add_term("x", Rat::one());
+        p.setObjective(obj);
+        let mut cap = LpAffineExpressionExact::new();
+        cap.add_term("x", r(3, 1));
+        p.addConstraint("cap", cap, LpConstraintSense::Le, r(10, 1));
+        p.setBounds("x", Rat::zero(), None);
+
+        let status = p.solve();
+        assert_eq!(status, LpStatus::Optimal);
+        assert_eq!(p.objectiveValue, Some(r(10, 3)));
+        assert_eq!(p.variableValues.get("x"), Some(&r(10, 3)));
+        assert!(p.valid());
+        assert_eq!(p.roundedValue("x"), None);
+    }
+
+    #[test]
+    fn detects_infeasible_bounds() {
+        let mut p = ExactLpProblem::new(1);
+        let mut obj = LpAffineExpressionExact::new();
+        obj.add_term("x", Rat::one());
+        p.setObjective(obj);
+        p.setBounds("x", Rat::zero(), None);
+        let mut lo = LpAffineExpressionExact::new();
+        lo.add_term("x", Rat::one());
+        p.addConstraint("lo", lo, LpConstraintSense::Ge, r(5, 1));
+        let mut hi = LpAffineExpressionExact::new();
+        hi.add_term("x", Rat::one());
+        p.addConstraint("hi", hi, LpConstraintSense::Le, r(3, 1));
+
+        assert_eq!(p.solve(), LpStatus::Infeasible);
+    }
+
+    #[test]
+    fn rounded_value_is_exact_for_an_integral_result() {
+        let mut p = ExactLpProblem::new(-1);
+        let mut obj = LpAffineExpressionExact::new();
+        obj.add_term("x", Rat::one());
+        p.setObjective(obj);
+        let mut cap = LpAffineExpressionExact::new();
+        cap.add_term("x", Rat::one());
+        p.addConstraint("cap", cap, LpConstraintSense::Le, r(6, 1));
+        p.setBounds("x", Rat::zero(), None);
+
+        p.solve();
+        assert_eq!(p.roundedValue("x"), Some(r(6, 1)));
+    }
+}
@@ -1,6 +1,11 @@
 use pyo3::prelude::*;
+mod bb;
+pub mod exact;
 mod pulp;
-use pulp::LpVariable;
+mod simplex;
+pub use pulp::{
+    LpAffineExpression, LpCategory, LpConstraintSense, LpProblem, LpStatus, LpVariable,
+};
 
 /// Formats the sum of two numbers as string.
 #[pyfunction]
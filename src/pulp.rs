@@ -1,8 +1,47 @@
 use pyo3::types::PyAny;
 use pyo3::prelude::*;
-use std::collections::{HashMap, HashSet};
+use std::cmp::Ordering as CmpOrdering;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt;
 use std::ops::{Add, Sub, Mul, Div, Neg};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+use crate::bb;
+use crate::simplex::{self, BoundOverrides};
+
+/// Assigns each `LpVariable` a process-wide unique id at construction time.
+/// This is a bare atomic counter, not a name table, so `LpVariable::init`
+/// never takes a lock and there is nothing here for a long-lived process to
+/// grow unboundedly. The id only needs to be unique and comparable -- it
+/// lets `LpAffineExpression::terms` merge/sort by a plain integer compare in
+/// `addInPlace` instead of hashing and cloning `String`s. Once a variable is
+/// added to an `LpProblem`, that problem resolves the id through its own
+/// local table (see `LpProblem::id_to_local`), not through anything here.
+static NEXT_VAR_ID: AtomicU32 = AtomicU32::new(0);
+
+fn next_var_id() -> u32 {
+    NEXT_VAR_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Names for variables that have been added to at least one `LpProblem`,
+/// keyed by construction id. Only `LpAffineExpression::atom`/`Display` read
+/// this -- they have no `LpProblem` to resolve a term's id through -- and
+/// only `LpProblem::addVariable` writes it, so it stays off the hot
+/// construction and evaluation paths.
+fn debug_names() -> &'static Mutex<HashMap<u32, String>> {
+    static NAMES: OnceLock<Mutex<HashMap<u32, String>>> = OnceLock::new();
+    NAMES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn resolve(id: u32) -> String {
+    debug_names()
+        .lock()
+        .unwrap()
+        .get(&id)
+        .cloned()
+        .unwrap_or_else(|| format!("_V{id}"))
+}
 
 #[pyclass(eq, eq_int)]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -33,6 +72,10 @@ pub enum LpStatus {
     Optimal,
     Infeasible,
     Unbounded,
+    // Search stopped (e.g. a branch-and-bound node limit) before the result
+    // could be proven optimal or infeasible -- mirrors real PuLP's
+    // "Undefined" status for exactly this case.
+    Undefined,
     // ... add more as needed
 }
 
@@ -40,6 +83,7 @@ pub enum LpStatus {
 #[derive(Debug, Clone)]
 pub struct LpVariable {
     pub name: String,
+    pub id: u32,
     pub lowBound: Option<f64>,
     pub upBound: Option<f64>,
     pub cat: LpCategory,
@@ -61,6 +105,7 @@ impl LpVariable {
         };
         Self {
             name: name.to_string(),
+            id: next_var_id(),
             lowBound,
             upBound,
             cat,
@@ -243,20 +288,20 @@ impl LpVariable {
 #[pyclass]
 #[derive(Debug, Clone)]
 pub struct LpAffineExpression {
-    pub terms: HashMap<String, f64>, // variable name -> coefficient
+    pub terms: Vec<(u32, f64)>, // (variable id, coefficient), kept sorted by id
     pub constant: f64,
 }
 
 impl LpAffineExpression {
     fn from_variable(var: &LpVariable) -> Self {
         Self {
-            terms: HashMap::from([(var.name.clone(), 1.0)]),
+            terms: vec![(var.id, 1.0)],
             constant: 0.0,
         }
     }
     fn from_constant(c: f64) -> Self {
         Self {
-            terms: HashMap::new(),
+            terms: Vec::new(),
             constant: c,
         }
     }
@@ -271,54 +316,80 @@ impl LpAffineExpression {
 
 #[pymethods]
 impl LpAffineExpression {
-    
+
     #[new]
     pub fn init() -> Self {
         Self {
-            terms: HashMap::new(),
+            terms: Vec::new(),
             constant: 0.0,
         }
     }
 
     pub fn add_term(&mut self, var: &LpVariable, coeff: f64) {
-        *self.terms.entry(var.name.clone()).or_insert(0.0) += coeff;
+        match self.terms.binary_search_by_key(&var.id, |(id, _)| *id) {
+            Ok(idx) => self.terms[idx].1 += coeff,
+            Err(idx) => self.terms.insert(idx, (var.id, coeff)),
+        }
     }
 
     pub fn isAtomic(&self) -> bool {
-        self.terms.len() == 1 && self.constant == 0.0 && self.terms.values().next() == Some(&1.0)
+        self.terms.len() == 1 && self.constant == 0.0 && self.terms[0].1 == 1.0
     }
 
     pub fn isNumericalConstant(&self) -> bool {
         self.terms.is_empty()
     }
 
-    pub fn atom(&self) -> Option<&String> {
-        self.terms.keys().next()
+    pub fn atom(&self) -> Option<String> {
+        self.terms.first().map(|(id, _)| resolve(*id))
     }
 
-    pub fn value(&self, vars: &HashMap<String, LpVariable>) -> Option<f64> {
+    pub fn value(&self, problem: &LpProblem) -> Option<f64> {
         let mut s = self.constant;
-        for (name, coeff) in &self.terms {
-            let v = vars.get(name)?.varValue?;
+        for (id, coeff) in &self.terms {
+            let v = problem.variable(*id)?.varValue?;
             s += v * coeff;
         }
         Some(s)
     }
 
-    pub fn valueOrDefault(&self, vars: &HashMap<String, LpVariable>) -> f64 {
+    pub fn valueOrDefault(&self, problem: &LpProblem) -> f64 {
         let mut s = self.constant;
-        for (name, coeff) in &self.terms {
-            let v = vars.get(name).map(|v| v.valueOrDefault()).unwrap_or(0.0);
+        for (id, coeff) in &self.terms {
+            let v = problem.variable(*id).map(|v| v.valueOrDefault()).unwrap_or(0.0);
             s += v * coeff;
         }
         s
     }
 
+    /// Merges `other` into `self` (scaled by `sign`) with a single linear
+    /// pass over both sorted term vectors -- no hashing, no string clones.
     pub fn addInPlace(&mut self, other: &LpAffineExpression, sign: f64) {
         self.constant += other.constant * sign;
-        for (k, v) in &other.terms {
-            *self.terms.entry(k.clone()).or_insert(0.0) += v * sign;
+        let mut merged = Vec::with_capacity(self.terms.len() + other.terms.len());
+        let (mut i, mut j) = (0, 0);
+        while i < self.terms.len() && j < other.terms.len() {
+            let (id_a, c_a) = self.terms[i];
+            let (id_b, c_b) = other.terms[j];
+            match id_a.cmp(&id_b) {
+                CmpOrdering::Less => {
+                    merged.push((id_a, c_a));
+                    i += 1;
+                }
+                CmpOrdering::Greater => {
+                    merged.push((id_b, c_b * sign));
+                    j += 1;
+                }
+                CmpOrdering::Equal => {
+                    merged.push((id_a, c_a + c_b * sign));
+                    i += 1;
+                    j += 1;
+                }
+            }
         }
+        merged.extend_from_slice(&self.terms[i..]);
+        merged.extend(other.terms[j..].iter().map(|(id, c)| (*id, c * sign)));
+        self.terms = merged;
     }
 
     pub fn subInPlace(&mut self, other: &LpAffineExpression) {
@@ -345,7 +416,7 @@ impl Neg for LpAffineExpression {
     type Output = Self;
     fn neg(mut self) -> Self::Output {
         self.constant = -self.constant;
-        for v in self.terms.values_mut() {
+        for (_, v) in self.terms.iter_mut() {
             *v = -*v;
         }
         self
@@ -355,7 +426,7 @@ impl Mul<f64> for LpAffineExpression {
     type Output = Self;
     fn mul(mut self, rhs: f64) -> Self::Output {
         self.constant *= rhs;
-        for v in self.terms.values_mut() {
+        for (_, v) in self.terms.iter_mut() {
             *v *= rhs;
         }
         self
@@ -365,7 +436,7 @@ impl Div<f64> for LpAffineExpression {
     type Output = Self;
     fn div(mut self, rhs: f64) -> Self::Output {
         self.constant /= rhs;
-        for v in self.terms.values_mut() {
+        for (_, v) in self.terms.iter_mut() {
             *v /= rhs;
         }
         self
@@ -408,16 +479,16 @@ impl LpConstraint {
         }
     }
 
-    pub fn value(&self, vars: &HashMap<String, LpVariable>) -> Option<f64> {
-        self.expr.value(vars)
+    pub fn value(&self, problem: &LpProblem) -> Option<f64> {
+        self.expr.value(problem)
     }
 
-    pub fn valueOrDefault(&self, vars: &HashMap<String, LpVariable>) -> f64 {
-        self.expr.valueOrDefault(vars)
+    pub fn valueOrDefault(&self, problem: &LpProblem) -> f64 {
+        self.expr.valueOrDefault(problem)
     }
 
-    pub fn valid(&self, vars: &HashMap<String, LpVariable>, eps: f64) -> bool {
-        let val = self.value(vars).unwrap_or(0.0);
+    pub fn valid(&self, problem: &LpProblem, eps: f64) -> bool {
+        let val = self.value(problem).unwrap_or(0.0);
         match self.sense {
             LpConstraintSense::Eq => val.abs() <= eps,
             LpConstraintSense::Le => val <= self.rhs + eps,
@@ -432,7 +503,17 @@ pub struct LpProblem {
     pub sense: i32, // 1=min, -1=max
     pub objective: Option<LpAffineExpression>,
     pub constraints: HashMap<String, LpConstraint>,
-    pub variables: HashMap<String, LpVariable>,
+    // Dense, insertion-ordered storage resolved through `variable_index`
+    // (name -> position) for name lookups, and `id_to_local` for id lookups.
+    // `id_to_local` is a `VecDeque` slotted at `id - id_base`, not a
+    // `HashMap`, so resolving an expression term's variable id is a direct
+    // index rather than a hash -- and, being scoped to this problem, its
+    // size tracks the span of ids *this problem's* variables happen to
+    // occupy rather than every id ever handed out process-wide.
+    variables: Vec<LpVariable>,
+    variable_index: HashMap<String, usize>,
+    id_base: Option<u32>,
+    id_to_local: VecDeque<Option<usize>>,
     pub status: LpStatus,
 }
 
@@ -443,13 +524,49 @@ impl LpProblem {
             sense,
             objective: None,
             constraints: HashMap::new(),
-            variables: HashMap::new(),
+            variables: Vec::new(),
+            variable_index: HashMap::new(),
+            id_base: None,
+            id_to_local: VecDeque::new(),
             status: LpStatus::NotSolved,
         }
     }
 
     pub fn addVariable(&mut self, variable: LpVariable) {
-        self.variables.insert(variable.name.clone(), variable);
+        debug_names()
+            .lock()
+            .unwrap()
+            .insert(variable.id, variable.name.clone());
+
+        match self.id_base {
+            None => {
+                self.id_base = Some(variable.id);
+                self.id_to_local.push_back(None);
+            }
+            Some(base) if variable.id < base => {
+                for _ in 0..(base - variable.id) {
+                    self.id_to_local.push_front(None);
+                }
+                self.id_base = Some(variable.id);
+            }
+            Some(base) => {
+                let offset = (variable.id - base) as usize;
+                while self.id_to_local.len() <= offset {
+                    self.id_to_local.push_back(None);
+                }
+            }
+        }
+        let offset = (variable.id - self.id_base.unwrap()) as usize;
+
+        if let Some(&idx) = self.variable_index.get(&variable.name) {
+            self.id_to_local[offset] = Some(idx);
+            self.variables[idx] = variable;
+        } else {
+            let idx = self.variables.len();
+            self.variable_index.insert(variable.name.clone(), idx);
+            self.id_to_local[offset] = Some(idx);
+            self.variables.push(variable);
+        }
     }
 
     pub fn addVariables(&mut self, variables: Vec<LpVariable>) {
@@ -467,7 +584,27 @@ impl LpProblem {
     }
 
     pub fn variables(&self) -> Vec<&LpVariable> {
-        self.variables.values().collect()
+        self.variables.iter().collect()
+    }
+
+    /// Resolves an `LpAffineExpression` term's variable id to the variable in
+    /// this problem via a direct `id_to_local` index -- no hashing, and no
+    /// process-wide table, on the evaluation hot path.
+    pub fn variable(&self, var_id: u32) -> Option<&LpVariable> {
+        let base = self.id_base?;
+        if var_id < base {
+            return None;
+        }
+        let offset = (var_id - base) as usize;
+        self.id_to_local
+            .get(offset)
+            .copied()
+            .flatten()
+            .map(|idx| &self.variables[idx])
+    }
+
+    pub fn variable_by_name(&self, name: &str) -> Option<&LpVariable> {
+        self.variable_index.get(name).map(|&idx| &self.variables[idx])
     }
 
     pub fn constraints(&self) -> Vec<&LpConstraint> {
@@ -475,11 +612,28 @@ impl LpProblem {
     }
 
     pub fn solve(&mut self) -> LpStatus {
-        // Stub: integrate with solver here
-        self.status = LpStatus::Optimal;
+        let has_integer_vars = self.variables.iter().any(|v| v.isInteger());
+        let solution = if has_integer_vars {
+            bb::solve_mip(self)
+        } else {
+            simplex::solve_relaxation(self, &BoundOverrides::new())
+        };
+        self.apply_solution(&solution);
         self.status
     }
 
+    fn apply_solution(&mut self, solution: &simplex::LpSolution) {
+        self.status = solution.status;
+        for var in self.variables.iter_mut() {
+            var.varValue = solution.values.get(&var.name).copied();
+            var.dj = solution.dj.get(&var.name).copied();
+        }
+        for (name, cons) in self.constraints.iter_mut() {
+            cons.pi = solution.pi.get(name).copied();
+            cons.slack = solution.slack.get(name).copied();
+        }
+    }
+
     pub fn numVariables(&self) -> usize {
         self.variables.len()
     }
@@ -489,13 +643,13 @@ impl LpProblem {
     }
 
     pub fn valid(&self, eps: f64) -> bool {
-        for v in self.variables.values() {
+        for v in &self.variables {
             if !v.valid(eps) {
                 return false;
             }
         }
         for c in self.constraints.values() {
-            if !c.valid(&self.variables, eps) {
+            if !c.valid(self, eps) {
                 return false;
             }
         }
@@ -508,8 +662,8 @@ impl LpProblem {
         .map(|v| v.infeasibilityGap(mip).abs()).collect::<Vec<_>>();
 
     let gaps_cons = self.constraints.values()
-        .filter(|c| !c.valid(&self.variables, 0.0))
-        .map(|c| c.value(&self.variables).unwrap_or(0.0).abs()).collect::<Vec<_>>();
+        .filter(|c| !c.valid(self, 0.0))
+        .map(|c| c.value(self).unwrap_or(0.0).abs()).collect::<Vec<_>>();
 
     gaps_vars.iter().chain(gaps_cons.iter()).fold(0.0, |acc, x| acc.max(*x))
     }
@@ -537,8 +691,8 @@ impl fmt::Display for LpVariable {
 impl fmt::Display for LpAffineExpression {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut s = String::new();
-        for (var, coeff) in &self.terms {
-            s.push_str(&format!("{}*{} + ", coeff, var));
+        for (id, coeff) in &self.terms {
+            s.push_str(&format!("{}*{} + ", coeff, resolve(*id)));
         }
         s.push_str(&format!("{}", self.constant));
         write!(f, "{}", s)
@@ -567,7 +721,7 @@ impl fmt::Display for LpProblem {
             writeln!(f, "{}: {}", name, c)?;
         }
         writeln!(f, "VARIABLES")?;
-        for v in self.variables.values() {
+        for v in &self.variables {
             writeln!(f, "{}", v)?;
         }
         Ok(())
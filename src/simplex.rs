@@ -0,0 +1,609 @@
+//! A self-contained dense two-phase primal simplex, built fresh for every
+//! `solve()` call. There is no external LP dependency (mirrors the approach
+//! of crates like `minilp`, but lives directly in this crate): we turn the
+//! model into standard form, run phase 1 to find a basic feasible solution,
+//! then phase 2 to optimize the real objective.
+
+use crate::pulp::{LpConstraintSense, LpProblem, LpStatus};
+use std::collections::HashMap;
+
+const EPS: f64 = 1e-7;
+
+/// Per-variable bound overrides layered on top of each `LpVariable`'s own
+/// `lowBound`/`upBound`. Used by the branch-and-bound driver to tighten
+/// bounds at a node without mutating the problem itself.
+pub(crate) type BoundOverrides = HashMap<String, (Option<f64>, Option<f64>)>;
+
+/// Outcome of solving the continuous relaxation of a problem.
+pub(crate) struct LpSolution {
+    pub status: LpStatus,
+    pub objective: f64,
+    pub values: HashMap<String, f64>,
+    pub dj: HashMap<String, f64>,
+    pub pi: HashMap<String, f64>,
+    pub slack: HashMap<String, f64>,
+}
+
+impl LpSolution {
+    fn infeasible() -> Self {
+        Self {
+            status: LpStatus::Infeasible,
+            objective: 0.0,
+            values: HashMap::new(),
+            dj: HashMap::new(),
+            pi: HashMap::new(),
+            slack: HashMap::new(),
+        }
+    }
+}
+
+struct Row {
+    coeffs: Vec<f64>,
+    rhs: f64,
+    sense: LpConstraintSense,
+    cons_name: Option<String>,
+}
+
+/// Gauss-Jordan eliminates `pc` out of every row except `pr`, plus `obj`.
+fn pivot(tab: &mut [Vec<f64>], obj: &mut [f64], pr: usize, pc: usize) {
+    let pivot_val = tab[pr][pc];
+    for v in tab[pr].iter_mut() {
+        *v /= pivot_val;
+    }
+    let pivot_row = tab[pr].clone();
+    for (r, row) in tab.iter_mut().enumerate() {
+        if r == pr {
+            continue;
+        }
+        let factor = row[pc];
+        if factor != 0.0 {
+            for (c, v) in row.iter_mut().enumerate() {
+                *v -= factor * pivot_row[c];
+            }
+        }
+    }
+    let factor = obj[pc];
+    if factor != 0.0 {
+        for (c, v) in obj.iter_mut().enumerate() {
+            *v -= factor * pivot_row[c];
+        }
+    }
+}
+
+/// Drives `obj` (a reduced-cost row, to be minimized) to optimality via the
+/// Dantzig rule, pivoting `tab`/`basis` in step. Returns `false` if the ratio
+/// test ever fails to find a leaving row (unbounded).
+fn run_simplex(
+    tab: &mut [Vec<f64>],
+    obj: &mut [f64],
+    basis: &mut [usize],
+    cols_total: usize,
+    is_artificial: &[bool],
+) -> bool {
+    loop {
+        let mut enter = None;
+        let mut best = -EPS;
+        for j in 0..cols_total {
+            if is_artificial[j] {
+                continue;
+            }
+            if obj[j] < best {
+                best = obj[j];
+                enter = Some(j);
+            }
+        }
+        let Some(pc) = enter else {
+            return true;
+        };
+
+        let mut leave = None;
+        let mut best_ratio = f64::INFINITY;
+        for i in 0..tab.len() {
+            let a = tab[i][pc];
+            if a > EPS {
+                let ratio = tab[i][cols_total] / a;
+                let better = ratio < best_ratio - 1e-9
+                    || ((ratio - best_ratio).abs() <= 1e-9
+                        && leave.map_or(true, |l| basis[i] < basis[l]));
+                if better {
+                    best_ratio = ratio;
+                    leave = Some(i);
+                }
+            }
+        }
+        let Some(pr) = leave else {
+            return false;
+        };
+        pivot(tab, obj, pr, pc);
+        basis[pr] = pc;
+    }
+}
+
+/// Where a variable's value lives in the tableau's column space.
+///
+/// A variable with a finite `lowBound` is shifted so its effective lower
+/// bound is 0 and occupies a single nonnegative column (`pos`). A genuinely
+/// free variable (no `lowBound`) has no such shift to apply, so it is split
+/// into `x = pos - neg`, the classic two-nonnegative-column encoding -- both
+/// halves are simplex variables in their own right, and the difference of
+/// their values is read back as `x`.
+struct VarCols {
+    pos: usize,
+    neg: Option<usize>,
+    shift: f64,
+}
+
+/// Solves the continuous relaxation of `problem` via a dense two-phase
+/// primal simplex, with `overrides` applied on top of each variable's own
+/// bounds (variables absent from `overrides` keep their stored bounds).
+///
+/// A finite `upBound` becomes an extra `<=` row. Each real constraint
+/// becomes a slack (`Le`), surplus+artificial (`Ge`), or artificial-only
+/// (`Eq`) row.
+pub(crate) fn solve_relaxation(problem: &LpProblem, overrides: &BoundOverrides) -> LpSolution {
+    let mut names: Vec<String> = problem.variables().iter().map(|v| v.name.clone()).collect();
+    names.sort();
+    let n_vars = names.len();
+    let index: HashMap<&str, usize> = names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| (name.as_str(), i))
+        .collect();
+
+    let mut var_cols: Vec<VarCols> = Vec::with_capacity(n_vars);
+    let mut up_of: Vec<Option<f64>> = vec![None; n_vars];
+    let mut next_col = 0usize;
+    for name in &names {
+        let (low_opt, up) = if let Some((lo, up)) = overrides.get(name) {
+            (*lo, *up)
+        } else {
+            let v = problem
+                .variable_by_name(name)
+                .expect("name came from problem.variables()");
+            (v.lowBound, v.upBound)
+        };
+        let pos = next_col;
+        next_col += 1;
+        let (neg, shift, up_shifted) = match low_opt {
+            Some(low) => (None, low, up.map(|u| u - low)),
+            None => {
+                // Genuinely free: no lower bound to shift against, so split
+                // into pos - neg rather than guessing a default.
+                let neg = next_col;
+                next_col += 1;
+                (Some(neg), 0.0, up)
+            }
+        };
+        var_cols.push(VarCols { pos, neg, shift });
+        up_of[var_cols.len() - 1] = up_shifted;
+    }
+    let n_cols = next_col;
+
+    let mut cons_names: Vec<&String> = problem.constraints.keys().collect();
+    cons_names.sort();
+
+    let mut rows: Vec<Row> = Vec::with_capacity(cons_names.len() + n_vars);
+    for name in &cons_names {
+        let c = &problem.constraints[name.as_str()];
+        let mut coeffs = vec![0.0; n_cols];
+        let mut shift_adjust = 0.0f64;
+        for (var_id, coeff) in &c.expr.terms {
+            if let Some(var) = problem.variable(*var_id) {
+                if let Some(&j) = index.get(var.name.as_str()) {
+                    let cols = &var_cols[j];
+                    coeffs[cols.pos] += *coeff;
+                    if let Some(neg) = cols.neg {
+                        coeffs[neg] -= *coeff;
+                    }
+                    shift_adjust += coeff * cols.shift;
+                }
+            }
+        }
+        let rhs = c.rhs - c.expr.constant - shift_adjust;
+        rows.push(Row {
+            coeffs,
+            rhs,
+            sense: c.sense,
+            cons_name: Some((*name).clone()),
+        });
+    }
+    for (j, up) in up_of.iter().enumerate() {
+        if let Some(u) = up {
+            let cols = &var_cols[j];
+            let mut coeffs = vec![0.0; n_cols];
+            coeffs[cols.pos] = 1.0;
+            if let Some(neg) = cols.neg {
+                coeffs[neg] = -1.0;
+            }
+            rows.push(Row {
+                coeffs,
+                rhs: *u,
+                sense: LpConstraintSense::Le,
+                cons_name: None,
+            });
+        }
+    }
+
+    for row in rows.iter_mut() {
+        if row.rhs < 0.0 {
+            for c in row.coeffs.iter_mut() {
+                *c = -*c;
+            }
+            row.rhs = -row.rhs;
+            row.sense = match row.sense {
+                LpConstraintSense::Le => LpConstraintSense::Ge,
+                LpConstraintSense::Ge => LpConstraintSense::Le,
+                LpConstraintSense::Eq => LpConstraintSense::Eq,
+            };
+        }
+    }
+
+    let mut slack_col_of: Vec<Option<usize>> = vec![None; rows.len()];
+    let mut surplus_col_of: Vec<Option<usize>> = vec![None; rows.len()];
+    let mut artificial_col_of: Vec<Option<usize>> = vec![None; rows.len()];
+    let mut next_col = n_cols;
+    for (i, row) in rows.iter().enumerate() {
+        match row.sense {
+            LpConstraintSense::Le => {
+                slack_col_of[i] = Some(next_col);
+                next_col += 1;
+            }
+            LpConstraintSense::Ge => {
+                surplus_col_of[i] = Some(next_col);
+                next_col += 1;
+                artificial_col_of[i] = Some(next_col);
+                next_col += 1;
+            }
+            LpConstraintSense::Eq => {
+                artificial_col_of[i] = Some(next_col);
+                next_col += 1;
+            }
+        }
+    }
+    let cols_total = next_col;
+    let mut is_artificial = vec![false; cols_total];
+    for c in artificial_col_of.iter().flatten() {
+        is_artificial[*c] = true;
+    }
+
+    let mut tab = vec![vec![0.0; cols_total + 1]; rows.len()];
+    let mut basis = vec![0usize; rows.len()];
+    let mut row_of_constraint: HashMap<String, usize> = HashMap::new();
+    for (i, row) in rows.iter().enumerate() {
+        tab[i][..n_cols].copy_from_slice(&row.coeffs);
+        if let Some(c) = slack_col_of[i] {
+            tab[i][c] = 1.0;
+            basis[i] = c;
+        }
+        if let Some(c) = surplus_col_of[i] {
+            tab[i][c] = -1.0;
+        }
+        if let Some(c) = artificial_col_of[i] {
+            tab[i][c] = 1.0;
+            basis[i] = c;
+        }
+        tab[i][cols_total] = row.rhs;
+        if let Some(name) = &row.cons_name {
+            row_of_constraint.insert(name.clone(), i);
+        }
+    }
+
+    // Phase 1: minimize the sum of artificials to find a basic feasible solution.
+    if artificial_col_of.iter().any(Option::is_some) {
+        let mut w = vec![0.0f64; cols_total + 1];
+        for (j, is_art) in is_artificial.iter().enumerate() {
+            w[j] = if *is_art { 1.0 } else { 0.0 };
+        }
+        for i in 0..rows.len() {
+            if is_artificial[basis[i]] {
+                for c in 0..=cols_total {
+                    w[c] -= tab[i][c];
+                }
+            }
+        }
+        run_simplex(&mut tab, &mut w, &mut basis, cols_total, &is_artificial);
+
+        let phase1_obj: f64 = (0..rows.len())
+            .filter(|&i| is_artificial[basis[i]])
+            .map(|i| tab[i][cols_total])
+            .sum();
+        if phase1_obj > 1e-6 {
+            return LpSolution::infeasible();
+        }
+    }
+
+    // Phase 2: optimize the real objective; artificials stay locked out of entry.
+    let mut c2 = vec![0.0f64; cols_total];
+    if let Some(obj) = &problem.objective {
+        let sense_factor = if problem.sense == -1 { -1.0 } else { 1.0 };
+        for (var_id, coeff) in &obj.terms {
+            if let Some(var) = problem.variable(*var_id) {
+                if let Some(&j) = index.get(var.name.as_str()) {
+                    let cols = &var_cols[j];
+                    c2[cols.pos] += sense_factor * *coeff;
+                    if let Some(neg) = cols.neg {
+                        c2[neg] -= sense_factor * *coeff;
+                    }
+                }
+            }
+        }
+    }
+    let mut z = vec![0.0f64; cols_total + 1];
+    z[..cols_total].copy_from_slice(&c2);
+    for i in 0..rows.len() {
+        let cb = c2[basis[i]];
+        if cb != 0.0 {
+            for c in 0..=cols_total {
+                z[c] -= cb * tab[i][c];
+            }
+        }
+    }
+
+    // Drive any artificial still in the basis (at value 0, since phase 1
+    // reached a zero objective) out before phase 2: pivot() folds every
+    // row's rhs into later pivots, so an artificial left basic can pick up
+    // a hidden nonzero value that never shows up in the reported solution.
+    for i in 0..rows.len() {
+        if !is_artificial[basis[i]] {
+            continue;
+        }
+        let pc = (0..cols_total).find(|&c| !is_artificial[c] && tab[i][c].abs() > EPS);
+        if let Some(pc) = pc {
+            pivot(&mut tab, &mut z, i, pc);
+            basis[i] = pc;
+        }
+        // else: row is redundant (linearly dependent on the others); leave
+        // the artificial basic at 0, which is harmless since it can never
+        // re-enter.
+    }
+
+    let optimal = run_simplex(&mut tab, &mut z, &mut basis, cols_total, &is_artificial);
+    if !optimal {
+        return LpSolution {
+            status: LpStatus::Unbounded,
+            ..LpSolution::infeasible()
+        };
+    }
+
+    let mut col_values = vec![0.0f64; n_cols];
+    for (i, &b) in basis.iter().enumerate() {
+        if b < n_cols {
+            col_values[b] = tab[i][cols_total];
+        }
+    }
+
+    let mut values = HashMap::new();
+    let mut dj = HashMap::new();
+    for (j, name) in names.iter().enumerate() {
+        let cols = &var_cols[j];
+        let value = match cols.neg {
+            Some(neg) => col_values[cols.pos] - col_values[neg],
+            None => col_values[cols.pos] + cols.shift,
+        };
+        values.insert(name.clone(), value);
+
+        // `z[neg] == -z[pos]` always holds for a free variable's split
+        // columns (the reduced-cost functional is linear and neg's column
+        // is exactly pos's negated), so `pos` alone gives the reduced cost
+        // of the original variable in both cases.
+        let reduced = z[cols.pos];
+        dj.insert(
+            name.clone(),
+            if problem.sense == -1 { -reduced } else { reduced },
+        );
+    }
+
+    let mut pi = HashMap::new();
+    let mut slack = HashMap::new();
+    for name in &cons_names {
+        let i = row_of_constraint[name.as_str()];
+        let dual_raw = if let Some(sc) = slack_col_of[i] {
+            z[sc]
+        } else if let Some(sp) = surplus_col_of[i] {
+            -z[sp]
+        } else {
+            z[artificial_col_of[i].expect("every row has a slack, surplus, or artificial column")]
+        };
+        pi.insert(
+            (*name).clone(),
+            if problem.sense == -1 { -dual_raw } else { dual_raw },
+        );
+
+        let c = &problem.constraints[name.as_str()];
+        let lhs = c
+            .expr
+            .terms
+            .iter()
+            .map(|(var_id, coeff)| {
+                let v = problem
+                    .variable(*var_id)
+                    .map(|var| values.get(&var.name).copied().unwrap_or(0.0))
+                    .unwrap_or(0.0);
+                v * *coeff
+            })
+            .sum::<f64>()
+            + c.expr.constant;
+        let s = match c.sense {
+            LpConstraintSense::Le => c.rhs - lhs,
+            LpConstraintSense::Ge => lhs - c.rhs,
+            LpConstraintSense::Eq => 0.0,
+        };
+        slack.insert((*name).clone(), s);
+    }
+
+    let objective = problem
+        .objective
+        .as_ref()
+        .map(|obj| {
+            obj.constant
+                + obj
+                    .terms
+                    .iter()
+                    .map(|(var_id, coeff)| {
+                        let v = problem
+                            .variable(*var_id)
+                            .map(|var| values.get(&var.name).copied().unwrap_or(0.0))
+                            .unwrap_or(0.0);
+                        v * *coeff
+                    })
+                    .sum::<f64>()
+        })
+        .unwrap_or(0.0);
+
+    LpSolution {
+        status: LpStatus::Optimal,
+        objective,
+        values,
+        dj,
+        pi,
+        slack,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pulp::{LpAffineExpression, LpCategory, LpConstraint, LpProblem, LpVariable};
+
+    fn var(name: &str, low: Option<f64>, up: Option<f64>) -> LpVariable {
+        LpVariable::init(name, low, up, LpCategory::Continuous)
+    }
+
+    fn expr(terms: &[(&LpVariable, f64)]) -> LpAffineExpression {
+        let mut e = LpAffineExpression::init();
+        for (v, c) in terms {
+            e.add_term(v, *c);
+        }
+        e
+    }
+
+    #[test]
+    fn solves_a_bounded_maximum_and_recovers_duals() {
+        let mut p = LpProblem::new("max", -1);
+        let x = var("x", Some(0.0), None);
+        let y = var("y", Some(0.0), None);
+        p.addVariable(x.clone());
+        p.addVariable(y.clone());
+        p.setObjective(expr(&[(&x, 1.0), (&y, 1.0)]));
+        p.addConstraint(
+            "cap",
+            LpConstraint::new(
+                expr(&[(&x, 1.0), (&y, 1.0)]),
+                LpConstraintSense::Le,
+                10.0,
+                Some("cap".into()),
+            ),
+        );
+        p.addConstraint(
+            "x_max",
+            LpConstraint::new(expr(&[(&x, 1.0)]), LpConstraintSense::Le, 6.0, Some("x_max".into())),
+        );
+        p.addConstraint(
+            "y_max",
+            LpConstraint::new(expr(&[(&y, 1.0)]), LpConstraintSense::Le, 8.0, Some("y_max".into())),
+        );
+
+        let sol = solve_relaxation(&p, &BoundOverrides::new());
+        assert_eq!(sol.status, LpStatus::Optimal);
+        assert!((sol.objective - 10.0).abs() < 1e-6);
+        assert!((sol.values.get("x").copied().unwrap() - 6.0).abs() < 1e-6);
+        assert!((sol.values.get("y").copied().unwrap() - 4.0).abs() < 1e-6);
+        // `cap` is binding at the optimum, so it must carry a nonzero shadow price.
+        assert!(sol.pi.get("cap").copied().unwrap().abs() > 1e-6);
+        assert!((sol.slack.get("cap").copied().unwrap()).abs() < 1e-6);
+        // `y_max` is slack (y=4 < 8).
+        assert!(sol.slack.get("y_max").copied().unwrap() > 1e-6);
+    }
+
+    #[test]
+    fn detects_infeasible_constraints() {
+        let mut p = LpProblem::new("infeasible", 1);
+        let x = var("x", Some(0.0), None);
+        p.addVariable(x.clone());
+        p.setObjective(expr(&[(&x, 1.0)]));
+        p.addConstraint(
+            "lo",
+            LpConstraint::new(expr(&[(&x, 1.0)]), LpConstraintSense::Ge, 5.0, Some("lo".into())),
+        );
+        p.addConstraint(
+            "hi",
+            LpConstraint::new(expr(&[(&x, 1.0)]), LpConstraintSense::Le, 3.0, Some("hi".into())),
+        );
+
+        let sol = solve_relaxation(&p, &BoundOverrides::new());
+        assert_eq!(sol.status, LpStatus::Infeasible);
+    }
+
+    #[test]
+    fn detects_an_unbounded_objective() {
+        let mut p = LpProblem::new("unbounded", -1);
+        let x = var("x", Some(0.0), None);
+        p.addVariable(x.clone());
+        p.setObjective(expr(&[(&x, 1.0)]));
+
+        let sol = solve_relaxation(&p, &BoundOverrides::new());
+        assert_eq!(sol.status, LpStatus::Unbounded);
+    }
+
+    #[test]
+    fn solves_a_free_variable_via_splitting() {
+        // x is unbounded both ways; minimize x s.t. x >= -7 drives it to the
+        // constraint's bound rather than the usual implicit lowBound of 0.
+        let mut p = LpProblem::new("free", 1);
+        let x = var("x", None, None);
+        p.addVariable(x.clone());
+        p.setObjective(expr(&[(&x, 1.0)]));
+        p.addConstraint(
+            "floor",
+            LpConstraint::new(expr(&[(&x, 1.0)]), LpConstraintSense::Ge, -7.0, Some("floor".into())),
+        );
+
+        let sol = solve_relaxation(&p, &BoundOverrides::new());
+        assert_eq!(sol.status, LpStatus::Optimal);
+        assert!((sol.values.get("x").copied().unwrap() - -7.0).abs() < 1e-6);
+        assert!((sol.objective - -7.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn solves_a_free_variable_with_a_finite_upper_bound() {
+        // x is free below but capped above at 3; maximizing x must hit that
+        // cap, exercising the extra `pos - neg <= up` row for a split variable.
+        let mut p = LpProblem::new("free-upper", -1);
+        let x = var("x", None, Some(3.0));
+        p.addVariable(x.clone());
+        p.setObjective(expr(&[(&x, 1.0)]));
+
+        let sol = solve_relaxation(&p, &BoundOverrides::new());
+        assert_eq!(sol.status, LpStatus::Optimal);
+        assert!((sol.values.get("x").copied().unwrap() - 3.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn drives_out_an_artificial_on_an_equality_constraint() {
+        // x + y = 10, x <= 4, minimize y: the equality forces an artificial
+        // into the initial basis, and y must end up basic (not stuck at its
+        // shifted lower bound of 0) for the reported point to satisfy x+y=10.
+        let mut p = LpProblem::new("eq", 1);
+        let x = var("x", Some(0.0), Some(4.0));
+        let y = var("y", Some(0.0), None);
+        p.addVariable(x.clone());
+        p.addVariable(y.clone());
+        p.setObjective(expr(&[(&y, 1.0)]));
+        p.addConstraint(
+            "total",
+            LpConstraint::new(
+                expr(&[(&x, 1.0), (&y, 1.0)]),
+                LpConstraintSense::Eq,
+                10.0,
+                Some("total".into()),
+            ),
+        );
+
+        let sol = solve_relaxation(&p, &BoundOverrides::new());
+        assert_eq!(sol.status, LpStatus::Optimal);
+        let x_val = sol.values.get("x").copied().unwrap();
+        let y_val = sol.values.get("y").copied().unwrap();
+        assert!((x_val + y_val - 10.0).abs() < 1e-6);
+        assert!((y_val - 6.0).abs() < 1e-6);
+    }
+}